@@ -59,7 +59,7 @@ impl BitVec {
             // Unwrap is safe because an empty vec would have no padding because it has no bytes
             let last_byte = self.raw_data.last_mut().unwrap();
 
-            *last_byte |= (bit as u8) << self.last_byte_padding - 1;
+            *last_byte |= (bit as u8) << (self.last_byte_padding - 1);
 
             self.last_byte_padding -= 1;
         }
@@ -78,20 +78,67 @@ impl BitVec {
 
         } else {
 
-            // The bits are not aligned
+            // The bits are not aligned, so shift each incoming byte to fill the
+            // free low bits of the current last byte, then carry the leftover
+            // high bits into a newly pushed byte.
 
-            // TODO: use a more efficient algorithm (complete byte buffering would be good)
+            let total_bits = self.len_bits() + bit_view.len_bits();
+            let filled = 8 - self.last_byte_padding;
+            let orig_padding = self.last_byte_padding;
 
-            for bit in bit_view.iter_bits() {
-                self.append_bit(bit)
+            for &byte in bit_view.raw_data {
+                *self.raw_data.last_mut().unwrap() |= byte >> filled;
+                self.raw_data.push(byte << orig_padding);
             }
 
+            // The last pushed byte may be entirely padding if the source's
+            // own partial last byte didn't carry any meaningful bits forward.
+            self.raw_data.truncate(least_bytes_repr_for_bits(total_bits));
+            self.last_byte_padding = ((8 - total_bits % 8) % 8) as u8;
+
+        }
+    }
+
+
+    /// Append the low `num_bits` of `value` to the `BitVec`, MSB-first.
+    /// Panics if `num_bits` is greater than 64.
+    pub fn append_bits(&mut self, value: u64, num_bits: u8) {
+
+        assert!(num_bits <= 64, "num_bits must be at most 64, got {num_bits}");
+
+        let mut remaining = num_bits;
+
+        // Fill the currently partially-used last byte, if any
+        if self.last_byte_padding != 0 && remaining != 0 {
+
+            let take = remaining.min(self.last_byte_padding);
+            let shift = remaining - take;
+            let bits = ((value >> shift) & ((1_u64 << take) - 1)) as u8;
+
+            let last_byte = self.raw_data.last_mut().unwrap();
+            *last_byte |= bits << (self.last_byte_padding - take);
+
+            self.last_byte_padding -= take;
+            remaining -= take;
+        }
+
+        // Write whole bytes
+        while remaining >= 8 {
+            remaining -= 8;
+            self.raw_data.push(((value >> remaining) & 0xff) as u8);
+        }
+
+        // Write the trailing partial byte
+        if remaining > 0 {
+            let bits = (value & ((1_u64 << remaining) - 1)) as u8;
+            self.raw_data.push(bits << (8 - remaining));
+            self.last_byte_padding = 8 - remaining;
         }
     }
 
 
     /// Construct a `BitView` that borrows `self`.
-    pub fn as_bit_view(&self) -> BitView {
+    pub fn as_bit_view(&self) -> BitView<'_> {
         BitView {
             raw_data: &self.raw_data,
             last_byte_padding: self.last_byte_padding
@@ -100,7 +147,7 @@ impl BitVec {
 
 
     /// Iterate over the meaningful bits
-    pub fn iter_bits(&self) -> BitIterator {
+    pub fn iter_bits(&self) -> BitIterator<'_> {
         BitIterator {
             bits: self.as_bit_view(),
             i: 0,
@@ -148,28 +195,490 @@ impl BitVec {
     }
 
 
-    /// Construct a `BitVec` from a valid sequence of bytes
-    pub fn deserialize(input: &[u8]) -> Result<Self, ()> {
+    /// Construct a `BitVec` from a valid sequence of bytes produced by [`BitVec::serialize`]
+    pub fn deserialize(input: &[u8]) -> Result<Self, DeserializeError> {
+
+        let last_byte_padding = *input.first().ok_or(DeserializeError::Empty)?;
+        let raw_data = &input[1..];
 
-        let last_byte_padding = *input.get(0).ok_or(())?;
+        validate_padding(last_byte_padding, raw_data.is_empty())?;
 
         Ok(Self {
-            raw_data: input[1..].to_vec(),
+            raw_data: raw_data.to_vec(),
             last_byte_padding
         })
     }
 
 
+    /// Serialize `self` into a compressed run-length / bit-packed hybrid representation,
+    /// similar to the one used for Parquet definition levels.
+    /// This is much smaller than [`BitVec::serialize`] for data that is mostly
+    /// runs of identical bits, at the cost of being slower to encode and decode.
+    pub fn serialize_rle(&self, buf: &mut Vec<u8>) {
+
+        let bits = self.to_bool_slice();
+        let n = bits.len();
+        let num_groups = least_bytes_repr_for_bits(n);
+
+        write_varint(buf, n as u64);
+
+        // Runs and literal groups are always counted in whole 8-bit groups (except that
+        // the very last group of the whole `BitVec` may hold fewer than 8 meaningful bits),
+        // so a literal group is never ambiguous to decode: it's always either fully
+        // meaningful or, if it's the last group overall, truncated by `total_bits`.
+        let mut g = 0;
+        while g < num_groups {
+
+            let run_groups = uniform_run_len_groups(&bits, num_groups, g);
+            let run_len_bits = ((g + run_groups) * 8).min(n) - g * 8;
+
+            if run_len_bits >= RLE_RUN_THRESHOLD {
+
+                write_varint(buf, (run_len_bits as u64) << 1);
+                buf.push(bits[g * 8] as u8);
+
+                g += run_groups;
+                continue;
+            }
+
+            // Literal: buffer consecutive groups until the next qualifying run
+            let literal_start_g = g;
+            g += 1;
+            while g < num_groups {
+                let run_groups = uniform_run_len_groups(&bits, num_groups, g);
+                let run_len_bits = ((g + run_groups) * 8).min(n) - g * 8;
+                if run_len_bits >= RLE_RUN_THRESHOLD {
+                    break;
+                }
+                g += 1;
+            }
+
+            let literal_groups = g - literal_start_g;
+            write_varint(buf, (literal_groups as u64) << 1 | 1);
+
+            for gi in literal_start_g..g {
+                let start = gi * 8;
+                let end = (start + 8).min(n);
+                let mut byte = 0_u8;
+                for (bit_i, &bit) in bits[start..end].iter().enumerate() {
+                    byte |= (bit as u8) << (7 - bit_i);
+                }
+                buf.push(byte);
+            }
+        }
+    }
+
+
+    /// Construct a `BitVec` from a sequence of bytes produced by [`BitVec::serialize_rle`]
+    pub fn deserialize_rle(input: &[u8]) -> Result<Self, DeserializeError> {
+
+        let mut pos = 0;
+        let total_bits = read_varint(input, &mut pos).ok_or(DeserializeError::Truncated)? as usize;
+
+        // Reject the header outright instead of silently truncating it: any run or
+        // literal count clamped below is still clamped *to this same total_bits*, so
+        // an inflated-but-uncapped total_bits would let the decode loop below run for
+        // as long as the (also attacker-controlled) header claims, regardless of how
+        // small `input` actually is.
+        if total_bits > RLE_MAX_TOTAL_BITS {
+            return Err(DeserializeError::TotalBitsTooLarge);
+        }
+
+        let mut result = Self::with_capacity(total_bits);
+
+        while result.len_bits() < total_bits {
+
+            let header = read_varint(input, &mut pos).ok_or(DeserializeError::Truncated)?;
+            let count = (header >> 1) as usize;
+            let remaining = total_bits - result.len_bits();
+
+            if header & 1 == 0 {
+
+                // RLE run: `count` repetitions of the single following bit value,
+                // clamped to the bits `total_bits` actually still calls for
+                let run_len = count.min(remaining);
+
+                let bit = *input.get(pos).ok_or(DeserializeError::Truncated)? != 0;
+                pos += 1;
+
+                for _ in 0..run_len {
+                    result.append_bit(bit);
+                }
+
+            } else {
+
+                // `count` verbatim 8-bit literal groups, clamped the same way
+                let literal_groups = count.min(least_bytes_repr_for_bits(remaining));
+
+                for _ in 0..literal_groups {
+                    let byte = *input.get(pos).ok_or(DeserializeError::Truncated)?;
+                    pos += 1;
+
+                    for bit_i in 0..8 {
+                        if result.len_bits() >= total_bits {
+                            break;
+                        }
+                        result.append_bit(byte & (1 << (7 - bit_i)) != 0);
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+
+    /// Bitwise AND of `self` and `other`.
+    /// Returns `Err(LengthMismatch)` if the two don't have the same `len_bits`.
+    pub fn and(&self, other: &BitView) -> Result<BitVec, LengthMismatch> {
+        self.as_bit_view().and(other)
+    }
+
+
+    /// Bitwise OR of `self` and `other`.
+    /// Returns `Err(LengthMismatch)` if the two don't have the same `len_bits`.
+    pub fn or(&self, other: &BitView) -> Result<BitVec, LengthMismatch> {
+        self.as_bit_view().or(other)
+    }
+
+
+    /// Bitwise XOR of `self` and `other`.
+    /// Returns `Err(LengthMismatch)` if the two don't have the same `len_bits`.
+    pub fn xor(&self, other: &BitView) -> Result<BitVec, LengthMismatch> {
+        self.as_bit_view().xor(other)
+    }
+
+
+    /// Bitwise NOT of `self`
+    pub fn not(&self) -> BitVec {
+        self.as_bit_view().not()
+    }
+
+
+    /// The number of set bits among the meaningful bits
+    pub fn count_ones(&self) -> usize {
+        self.as_bit_view().count_ones()
+    }
+
+
+    /// The number of set bits among the meaningful bits before index `i`
+    pub fn rank(&self, i: usize) -> usize {
+        self.as_bit_view().rank(i)
+    }
+
+
+    /// The index of the `n`-th set bit (0-indexed among the set bits), or `None`
+    /// if `self` has fewer than `n + 1` set bits
+    pub fn select(&self, n: usize) -> Option<usize> {
+        self.as_bit_view().select(n)
+    }
+
+
+    /// Get the bit at index `i`, or `None` if `i` is out of bounds
+    pub fn get_bit(&self, i: usize) -> Option<bool> {
+
+        if i >= self.len_bits() {
+            return None;
+        }
+
+        let byte = self.raw_data[i / 8];
+        let bit_in_byte_i = (i % 8) as u8;
+
+        Some((byte & (1_u8 << (7 - bit_in_byte_i))) != 0)
+    }
+
+
+    /// Set the bit at index `i`.
+    /// Panics if `i` is out of bounds.
+    pub fn set_bit(&mut self, i: usize, value: bool) {
+
+        assert!(i < self.len_bits(), "index out of bounds: the len is {} but the index is {}", self.len_bits(), i);
+
+        let byte = &mut self.raw_data[i / 8];
+        let bit_in_byte_i = (i % 8) as u8;
+        let mask = 1_u8 << (7 - bit_in_byte_i);
+
+        if value {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+
+
+    /// Remove and return the last bit, or `None` if the `BitVec` is empty.
+    /// The inverse of [`BitVec::append_bit`].
+    pub fn pop_bit(&mut self) -> Option<bool> {
+
+        let last_byte = self.raw_data.last_mut()?;
+
+        let bit = (*last_byte >> self.last_byte_padding) & 1 != 0;
+        *last_byte &= !(1_u8 << self.last_byte_padding);
+
+        self.last_byte_padding += 1;
+
+        if self.last_byte_padding == 8 {
+            self.raw_data.pop();
+            self.last_byte_padding = 0;
+        }
+
+        Some(bit)
+    }
+
+
+    /// Shrink the `BitVec` to the first `len_bits` bits.
+    /// Does nothing if `len_bits` is greater than or equal to the current `len_bits`.
+    pub fn truncate(&mut self, len_bits: usize) {
+
+        if len_bits >= self.len_bits() {
+            return;
+        }
+
+        self.raw_data.truncate(least_bytes_repr_for_bits(len_bits));
+        self.last_byte_padding = ((8 - len_bits % 8) % 8) as u8;
+
+        mask_last_byte_padding(&mut self.raw_data, self.last_byte_padding);
+    }
+
+
+    /// Append `additional_bits` copies of `value`
+    pub fn grow(&mut self, additional_bits: usize, value: bool) {
+
+        let fill: u64 = if value { u64::MAX } else { 0 };
+
+        let mut remaining = additional_bits;
+        while remaining > 64 {
+            self.append_bits(fill, 64);
+            remaining -= 64;
+        }
+
+        self.append_bits(fill, remaining as u8);
+    }
+
+
+    /// Reserve capacity for at least `additional_bits` more bits
+    pub fn reserve(&mut self, additional_bits: usize) {
+        self.raw_data.reserve(least_bytes_repr_for_bits(additional_bits));
+    }
+
+
+}
+
+
+impl Default for BitVec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut result = BitVec::new();
+        result.extend(iter);
+        result
+    }
+}
+
+
+impl Extend<bool> for BitVec {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        for bit in iter {
+            self.append_bit(bit);
+        }
+    }
+}
+
+
+/// The minimum length, in bits, of a run of identical bits for
+/// [`BitVec::serialize_rle`] to encode it as an RLE run instead of literal groups.
+const RLE_RUN_THRESHOLD: usize = 8;
+
+
+/// The largest `total_bits` that [`BitVec::deserialize_rle`] will accept in a single call.
+/// The `total_bits` header is attacker-controlled and is read long before it can be
+/// corroborated against `input` (a handful of bytes can legitimately RLE-encode a huge
+/// run), so this is an outright ceiling rather than just a cap on the upfront allocation:
+/// a header above it is rejected instead of being decoded.
+const RLE_MAX_TOTAL_BITS: usize = 1 << 26;
+
+
+/// Whether the 8-bit group at group index `g` (the last group may be shorter) holds
+/// a single repeated bit value
+fn uniform_group_value(bits: &[bool], n: usize, g: usize) -> Option<bool> {
+    let start = g * 8;
+    let end = (start + 8).min(n);
+    let value = bits[start];
+    bits[start..end].iter().all(|&bit| bit == value).then_some(value)
+}
+
+
+/// The number of consecutive groups starting at group index `g`, out of `num_groups`
+/// total, that are uniform and share the same repeated bit value as group `g`
+fn uniform_run_len_groups(bits: &[bool], num_groups: usize, g: usize) -> usize {
+    match uniform_group_value(bits, bits.len(), g) {
+        None => 0,
+        Some(value) => {
+            let mut count = 1;
+            while g + count < num_groups && uniform_group_value(bits, bits.len(), g + count) == Some(value) {
+                count += 1;
+            }
+            count
+        }
+    }
+}
+
+
+/// Write `value` as a little-endian base-128 varint
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf.push(byte);
+            break;
+        } else {
+            buf.push(byte | 0x80);
+        }
+    }
+}
+
+
+/// Read a little-endian base-128 varint starting at `*pos`, advancing `*pos` past it
+fn read_varint(input: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *input.get(*pos)?;
+        *pos += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Some(value)
 }
 
 
 pub const fn least_bytes_repr_for_bits(bit_count: usize) -> usize {
-    bit_count / 8 + (bit_count % 8 != 0) as usize
-} 
+    bit_count / 8 + !bit_count.is_multiple_of(8) as usize
+}
+
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for BitVec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("BitVec", 2)?;
+        state.serialize_field("last_byte_padding", &self.last_byte_padding)?;
+        state.serialize_field("raw_data", &self.raw_data)?;
+        state.end()
+    }
+}
+
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BitVec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "BitVec")]
+        struct Repr {
+            last_byte_padding: u8,
+            raw_data: Vec<u8>,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+
+        validate_padding(repr.last_byte_padding, repr.raw_data.is_empty())
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            raw_data: repr.raw_data,
+            last_byte_padding: repr.last_byte_padding
+        })
+    }
+}
+
+
+/// The reason a padded byte sequence could not be deserialized into a `BitVec` or `BitView`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeserializeError {
+    /// The input was empty: there was no padding byte to read
+    Empty,
+    /// The padding byte was 8 or greater, which is not a valid number of padding bits in a byte
+    InvalidPadding,
+    /// The input declared nonzero padding despite having no data bytes
+    PaddingWithoutData,
+    /// The input ended before a varint header or an expected data byte could be read
+    Truncated,
+    /// The decoded `total_bits` header of an RLE stream claimed more bits than this
+    /// implementation is willing to decode in one call
+    TotalBitsTooLarge,
+}
+
+impl std::fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "input is empty: missing the padding byte"),
+            Self::InvalidPadding => write!(f, "padding byte must be less than 8"),
+            Self::PaddingWithoutData => write!(f, "padding must be 0 when there is no data"),
+            Self::Truncated => write!(f, "input ended before an expected header or data byte"),
+            Self::TotalBitsTooLarge => write!(f, "RLE total_bits header exceeds RLE_MAX_TOTAL_BITS"),
+        }
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+
+/// Check that `last_byte_padding` is a valid padding value for a data buffer
+/// that is or isn't empty
+fn validate_padding(last_byte_padding: u8, data_is_empty: bool) -> Result<(), DeserializeError> {
+
+    if last_byte_padding >= 8 {
+        return Err(DeserializeError::InvalidPadding);
+    }
+
+    if data_is_empty && last_byte_padding != 0 {
+        return Err(DeserializeError::PaddingWithoutData);
+    }
+
+    Ok(())
+}
+
+
+/// The reason a bitwise combination of two `BitView`s (or `BitVec`s) could not be performed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthMismatch {
+    /// The length in bits of the left-hand operand
+    pub self_len_bits: usize,
+    /// The length in bits of the right-hand operand
+    pub other_len_bits: usize,
+}
+
+impl std::fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "bitwise op requires operands of equal length, got {} and {} bits",
+            self.self_len_bits, self.other_len_bits
+        )
+    }
+}
+
+impl std::error::Error for LengthMismatch {}
 
 
 /// A view into a sequence of bits
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct BitView<'a> {
 
     raw_data: &'a [u8],
@@ -188,7 +697,9 @@ impl<'a> BitView<'a> {
     }
 
 
-    /// Construct a `BitView` from bytes and a final padding value
+    /// Construct a `BitView` from bytes and a final padding value.
+    /// This does not validate the padding invariant; use [`BitView::try_from_padded_bytes`]
+    /// to construct from untrusted `bytes`/`last_byte_padding` pairs.
     pub const fn from_padded_bytes(bytes: &'a [u8], last_byte_padding: u8) -> BitView<'a> {
         Self {
             raw_data: bytes,
@@ -197,6 +708,16 @@ impl<'a> BitView<'a> {
     }
 
 
+    /// Construct a `BitView` from bytes and a final padding value, validating that
+    /// `last_byte_padding` is a valid padding value for `bytes`
+    pub fn try_from_padded_bytes(bytes: &'a [u8], last_byte_padding: u8) -> Result<BitView<'a>, DeserializeError> {
+
+        validate_padding(last_byte_padding, bytes.is_empty())?;
+
+        Ok(Self::from_padded_bytes(bytes, last_byte_padding))
+    }
+
+
     /// Construct a list of `bool` values from the contents of the view
     pub fn to_bool_slice(&self) -> Box<[bool]>{
         self.iter_bits()
@@ -220,7 +741,7 @@ impl<'a> BitView<'a> {
     /// Return the `BitView`'s underlying data as bytes and the padding of the last byte
     pub fn as_padded_bytes(&self) -> (&[u8], u8) {
         (
-            &self.raw_data,
+            self.raw_data,
             self.last_byte_padding
         )
     }
@@ -233,23 +754,195 @@ impl<'a> BitView<'a> {
 
         buf.push(self.last_byte_padding);
 
-        buf.extend_from_slice(&self.raw_data);
+        buf.extend_from_slice(self.raw_data);
 
         buf.into_boxed_slice()
     }
 
 
-    /// Construct a `BitView` from a valid sequence of bytes
-    pub fn deserialize(input: &'a [u8]) -> Result<BitView<'a>, ()> {
+    /// Construct a `BitView` from a sequence of bytes produced by [`BitView::serialize`]
+    /// or [`BitVec::serialize`].
+    /// This is a zero-copy parse: no allocation happens, and the returned `BitView`
+    /// borrows directly from `input`.
+    pub fn deserialize(input: &'a [u8]) -> Result<BitView<'a>, DeserializeError> {
+
+        let last_byte_padding = *input.first().ok_or(DeserializeError::Empty)?;
+        let raw_data = &input[1..];
 
-        let last_byte_padding = *input.get(0).ok_or(())?;
+        validate_padding(last_byte_padding, raw_data.is_empty())?;
 
         Ok(Self {
-            raw_data: &input[1..],
+            raw_data,
             last_byte_padding
         })
     }
 
+
+    /// Bitwise AND of `self` and `other`.
+    /// Returns `Err(LengthMismatch)` if the two views don't have the same `len_bits`.
+    pub fn and(&self, other: &BitView) -> Result<BitVec, LengthMismatch> {
+        self.zip_words(other, |a, b| a & b)
+    }
+
+
+    /// Bitwise OR of `self` and `other`.
+    /// Returns `Err(LengthMismatch)` if the two views don't have the same `len_bits`.
+    pub fn or(&self, other: &BitView) -> Result<BitVec, LengthMismatch> {
+        self.zip_words(other, |a, b| a | b)
+    }
+
+
+    /// Bitwise XOR of `self` and `other`.
+    /// Returns `Err(LengthMismatch)` if the two views don't have the same `len_bits`.
+    pub fn xor(&self, other: &BitView) -> Result<BitVec, LengthMismatch> {
+        self.zip_words(other, |a, b| a ^ b)
+    }
+
+
+    /// Bitwise NOT of `self`
+    pub fn not(&self) -> BitVec {
+
+        let mut raw_data = Vec::with_capacity(self.raw_data.len());
+
+        let mut chunks = self.raw_data.chunks_exact(8);
+
+        for chunk in &mut chunks {
+            let word = u64::from_ne_bytes(chunk.try_into().unwrap());
+            raw_data.extend_from_slice(&(!word).to_ne_bytes());
+        }
+
+        for &byte in chunks.remainder() {
+            raw_data.push(!byte);
+        }
+
+        mask_last_byte_padding(&mut raw_data, self.last_byte_padding);
+
+        BitVec {
+            raw_data,
+            last_byte_padding: self.last_byte_padding
+        }
+    }
+
+
+    /// Combine `self` and `other` a word (`u64`) at a time with `op`, falling back to a
+    /// byte-wise scalar tail for the bytes that don't fill a whole word, forcing trailing
+    /// padding bits to zero so they can never pollute the result
+    fn zip_words(&self, other: &BitView, op: impl Fn(u64, u64) -> u64) -> Result<BitVec, LengthMismatch> {
+
+        if self.len_bits() != other.len_bits() {
+            return Err(LengthMismatch {
+                self_len_bits: self.len_bits(),
+                other_len_bits: other.len_bits(),
+            });
+        }
+
+        let mut raw_data = Vec::with_capacity(self.raw_data.len());
+
+        let mut self_chunks = self.raw_data.chunks_exact(8);
+        let mut other_chunks = other.raw_data.chunks_exact(8);
+
+        for (a, b) in (&mut self_chunks).zip(&mut other_chunks) {
+            let word = op(u64::from_ne_bytes(a.try_into().unwrap()), u64::from_ne_bytes(b.try_into().unwrap()));
+            raw_data.extend_from_slice(&word.to_ne_bytes());
+        }
+
+        for (&a, &b) in self_chunks.remainder().iter().zip(other_chunks.remainder().iter()) {
+            raw_data.push(op(a as u64, b as u64) as u8);
+        }
+
+        mask_last_byte_padding(&mut raw_data, self.last_byte_padding);
+
+        Ok(BitVec {
+            raw_data,
+            last_byte_padding: self.last_byte_padding
+        })
+    }
+
+
+    /// The number of set bits among the meaningful bits of the view
+    pub fn count_ones(&self) -> usize {
+
+        if self.raw_data.is_empty() {
+            return 0;
+        }
+
+        let last_index = self.raw_data.len() - 1;
+        let mut chunks = self.raw_data.chunks_exact(8);
+        let mut byte_index = 0_usize;
+        let mut total = 0_usize;
+
+        for chunk in &mut chunks {
+            let mut bytes: [u8; 8] = chunk.try_into().unwrap();
+            if byte_index + 7 == last_index {
+                bytes[7] = mask_byte_padding(bytes[7], self.last_byte_padding);
+            }
+            total += u64::from_ne_bytes(bytes).count_ones() as usize;
+            byte_index += 8;
+        }
+
+        for &byte in chunks.remainder() {
+            let byte = if byte_index == last_index {
+                mask_byte_padding(byte, self.last_byte_padding)
+            } else {
+                byte
+            };
+            total += byte.count_ones() as usize;
+            byte_index += 1;
+        }
+
+        total
+    }
+
+
+    /// The number of set bits among the meaningful bits before index `i`
+    pub fn rank(&self, i: usize) -> usize {
+        self.iter_bits()
+            .take(i)
+            .filter(|&bit| bit)
+            .count()
+    }
+
+
+    /// The index of the `n`-th set bit (0-indexed among the set bits), or `None`
+    /// if the view has fewer than `n + 1` set bits
+    pub fn select(&self, n: usize) -> Option<usize> {
+        self.iter_bits()
+            .enumerate()
+            .filter(|&(_, bit)| bit)
+            .nth(n)
+            .map(|(i, _)| i)
+    }
+
+}
+
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for BitView<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("BitView", 2)?;
+        state.serialize_field("last_byte_padding", &self.last_byte_padding)?;
+        state.serialize_field("raw_data", &self.raw_data)?;
+        state.end()
+    }
+}
+
+
+/// Clear the low `last_byte_padding` bits (the padding bits) of `raw_data`'s last byte, if any
+fn mask_last_byte_padding(raw_data: &mut [u8], last_byte_padding: u8) {
+    if let Some(last_byte) = raw_data.last_mut() {
+        *last_byte = mask_byte_padding(*last_byte, last_byte_padding);
+    }
+}
+
+
+/// Clear the low `padding` bits of `byte`
+const fn mask_byte_padding(byte: u8, padding: u8) -> u8 {
+    byte & (0xff_u8 << padding)
 }
 
 
@@ -261,6 +954,33 @@ pub struct BitIterator<'a> {
 
 }
 
+impl<'a> BitIterator<'a> {
+
+    /// Consume up to `num_bits` bits and reassemble them MSB-first into a `u64`.
+    /// Returns `None`, without consuming any bits, if fewer than `num_bits` bits remain.
+    /// Panics if `num_bits` is greater than 64.
+    pub fn read_bits(&mut self, num_bits: u8) -> Option<u64> {
+
+        assert!(num_bits <= 64, "num_bits must be at most 64, got {num_bits}");
+
+        let start_i = self.i;
+
+        let mut value: u64 = 0;
+        for _ in 0..num_bits {
+            match self.next() {
+                Some(bit) => value = (value << 1) | bit as u64,
+                None => {
+                    self.i = start_i;
+                    return None;
+                }
+            }
+        }
+
+        Some(value)
+    }
+
+}
+
 impl<'a> Iterator for BitIterator<'a> {
     type Item = bool;
 
@@ -353,6 +1073,30 @@ mod tests {
     }
 
 
+    #[test]
+    fn check_extend_unaligned_offsets() {
+
+        // Cover all eight possible `last_byte_padding` offsets of `self`
+        // before extending, including the aligned (padding 0) case.
+        for first_len in 0..8 {
+            for second_len in 1..12 {
+
+                let a: Vec<bool> = (0..first_len).map(|i| i % 3 == 0).collect();
+                let b: Vec<bool> = (0..second_len).map(|i| i % 2 == 0).collect();
+
+                let mut va = BitVec::from_bool_slice(&a);
+                let vb = BitVec::from_bool_slice(&b);
+
+                va.extend_from_bits(&vb.as_bit_view());
+
+                let expected: Vec<bool> = a.iter().chain(b.iter()).copied().collect();
+
+                assert_eq!(*va.to_bool_slice(), *expected);
+            }
+        }
+    }
+
+
     #[test]
     fn check_serde() {
 
@@ -368,5 +1112,314 @@ mod tests {
         assert_eq!(v, des);
     }
 
+
+    #[test]
+    fn check_append_bits_and_read_bits() {
+
+        let mut v = BitVec::new();
+
+        // A leading unaligned bit, then a 3-bit tag, a 12-bit length and a
+        // full 8-bit byte, to exercise the partial/whole/trailing byte paths.
+        v.append_bit(true);
+        v.append_bits(0b101, 3);
+        v.append_bits(0xabc, 12);
+        v.append_bits(0xff, 8);
+
+        assert_eq!(v.len_bits(), 1 + 3 + 12 + 8);
+
+        let view = v.as_bit_view();
+        let mut iter = view.iter_bits();
+
+        assert_eq!(iter.read_bits(1), Some(1));
+        assert_eq!(iter.read_bits(3), Some(0b101));
+        assert_eq!(iter.read_bits(12), Some(0xabc));
+        assert_eq!(iter.read_bits(8), Some(0xff));
+        assert_eq!(iter.read_bits(1), None);
+    }
+
+
+    #[test]
+    fn check_append_bits_offsets() {
+
+        // Cover every starting alignment of the last byte
+        for first_len in 0..8 {
+
+            let prefix: Vec<bool> = (0..first_len).map(|i| i % 2 == 0).collect();
+
+            let mut v = BitVec::from_bool_slice(&prefix);
+            v.append_bits(0b1101_0110, 8);
+            v.append_bits(0b10, 2);
+
+            let mut expected = prefix.clone();
+            expected.extend([true, true, false, true, false, true, true, false]);
+            expected.extend([true, false]);
+
+            assert_eq!(*v.to_bool_slice(), *expected);
+        }
+    }
+
+
+    #[test]
+    fn check_rle_roundtrip_mostly_zeros() {
+
+        let mut bools = vec![false; 100];
+        bools[40] = true;
+        bools[41] = true;
+
+        let v = BitVec::from_bool_slice(&bools);
+
+        let mut ser = Vec::new();
+        v.serialize_rle(&mut ser);
+
+        // Should be much smaller than the plain serialization of 100 mostly-zero bits
+        assert!(ser.len() < 1 + v.least_len_bytes());
+
+        let des = BitVec::deserialize_rle(&ser).unwrap();
+
+        assert_eq!(v, des);
+    }
+
+
+    #[test]
+    fn check_rle_roundtrip_mixed() {
+
+        let bools: Vec<bool> = (0..77).map(|i| (i / 3) % 2 == 0).collect();
+
+        let v = BitVec::from_bool_slice(&bools);
+
+        let mut ser = Vec::new();
+        v.serialize_rle(&mut ser);
+
+        let des = BitVec::deserialize_rle(&ser).unwrap();
+
+        assert_eq!(v, des);
+    }
+
+
+    #[test]
+    fn check_rle_roundtrip_empty() {
+
+        let v = BitVec::new();
+
+        let mut ser = Vec::new();
+        v.serialize_rle(&mut ser);
+
+        let des = BitVec::deserialize_rle(&ser).unwrap();
+
+        assert_eq!(v, des);
+    }
+
+
+    #[test]
+    fn check_rle_deserialize_rejects_untrusted_total_bits() {
+
+        // A `total_bits` varint header claiming an enormous bit count, with nowhere
+        // near enough input left to back it up, must not attempt a huge allocation
+        // and must fail instead of succeeding or hanging.
+        let mut malicious = Vec::new();
+        write_varint(&mut malicious, u64::MAX);
+        malicious.extend_from_slice(&[0; 8]);
+
+        assert!(BitVec::deserialize_rle(&malicious).is_err());
+    }
+
+
+    #[test]
+    fn check_rle_deserialize_clamps_run_count_to_total_bits() {
+
+        // A run header claiming far more repetitions than `total_bits` allows for
+        // must be clamped to `total_bits`, not produce an oversized `BitVec`.
+        let mut malformed = Vec::new();
+        write_varint(&mut malformed, 5);
+        write_varint(&mut malformed, 100_000_000 << 1);
+        malformed.push(1);
+
+        let des = BitVec::deserialize_rle(&malformed).unwrap();
+
+        assert_eq!(des.len_bits(), 5);
+        assert_eq!(*des.to_bool_slice(), [true, true, true, true, true]);
+    }
+
+
+    #[test]
+    fn check_rle_deserialize_rejects_oversized_but_plausible_total_bits() {
+
+        // A `total_bits` header that's too large to decode but not so large that
+        // `with_capacity` alone would catch it (unlike `u64::MAX` above) must still
+        // be rejected outright, rather than letting the decode loop in
+        // `deserialize_rle` run for as long as the header claims.
+        let mut malicious = Vec::new();
+        write_varint(&mut malicious, 2_000_000_000);
+        write_varint(&mut malicious, 2_000_000_000 << 1);
+        malicious.push(1);
+
+        assert!(BitVec::deserialize_rle(&malicious).is_err());
+    }
+
+
+    #[test]
+    fn check_bitwise_ops() {
+
+        let a = [true, false, true, true, false, false, true, false, true, false];
+        let b = [true, true, false, true, false, true, true, false, true, true];
+
+        let va = BitVec::from_bool_slice(&a);
+        let vb = BitVec::from_bool_slice(&b);
+
+        let expected_and: Vec<bool> = a.iter().zip(b.iter()).map(|(&x, &y)| x && y).collect();
+        let expected_or: Vec<bool> = a.iter().zip(b.iter()).map(|(&x, &y)| x || y).collect();
+        let expected_xor: Vec<bool> = a.iter().zip(b.iter()).map(|(&x, &y)| x != y).collect();
+        let expected_not: Vec<bool> = a.iter().map(|&x| !x).collect();
+
+        assert_eq!(*va.and(&vb.as_bit_view()).unwrap().to_bool_slice(), *expected_and);
+        assert_eq!(*va.or(&vb.as_bit_view()).unwrap().to_bool_slice(), *expected_or);
+        assert_eq!(*va.xor(&vb.as_bit_view()).unwrap().to_bool_slice(), *expected_xor);
+        assert_eq!(*va.not().to_bool_slice(), *expected_not);
+    }
+
+
+    #[test]
+    fn check_bitwise_ops_length_mismatch() {
+
+        let va = BitVec::from_bool_slice(&[true, false, true]);
+        let vb = BitVec::from_bool_slice(&[true, false]);
+
+        assert!(va.and(&vb.as_bit_view()).is_err());
+        assert!(va.or(&vb.as_bit_view()).is_err());
+        assert!(va.xor(&vb.as_bit_view()).is_err());
+    }
+
+
+    #[test]
+    fn check_count_ones_rank_select() {
+
+        let bools = [true, false, true, true, false, false, true, false, true, false, true];
+
+        let v = BitVec::from_bool_slice(&bools);
+
+        assert_eq!(v.count_ones(), bools.iter().filter(|&&b| b).count());
+
+        for i in 0..=bools.len() {
+            assert_eq!(v.rank(i), bools[..i].iter().filter(|&&b| b).count());
+        }
+
+        let set_indices: Vec<usize> = bools.iter().enumerate().filter(|&(_, &b)| b).map(|(i, _)| i).collect();
+        for (n, &expected_i) in set_indices.iter().enumerate() {
+            assert_eq!(v.select(n), Some(expected_i));
+        }
+        assert_eq!(v.select(set_indices.len()), None);
+    }
+
+
+    #[test]
+    fn check_get_set_bit() {
+
+        let bools = [true, false, true, false, true, false, true, false, true, false, true];
+
+        let mut v = BitVec::from_bool_slice(&bools);
+
+        for (i, &b) in bools.iter().enumerate() {
+            assert_eq!(v.get_bit(i), Some(b));
+        }
+        assert_eq!(v.get_bit(bools.len()), None);
+
+        v.set_bit(2, false);
+        v.set_bit(3, true);
+
+        assert_eq!(v.get_bit(2), Some(false));
+        assert_eq!(v.get_bit(3), Some(true));
+    }
+
+
+    #[test]
+    fn check_pop_bit() {
+
+        let bools = [true, false, true, true, false, true, false, true, true];
+
+        let mut v = BitVec::from_bool_slice(&bools);
+
+        for &b in bools.iter().rev() {
+            assert_eq!(v.pop_bit(), Some(b));
+        }
+
+        assert_eq!(v.pop_bit(), None);
+        assert_eq!(v.len_bits(), 0);
+        assert_eq!(v.least_len_bytes(), 0);
+    }
+
+
+    #[test]
+    fn check_truncate() {
+
+        let bools = [true, false, true, true, false, true, false, true, true, false, true];
+
+        let mut v = BitVec::from_bool_slice(&bools);
+        v.truncate(5);
+
+        assert_eq!(*v.to_bool_slice(), bools[..5]);
+        assert_eq!(v.len_bits(), 5);
+
+        // Truncating to a length >= the current length is a no-op
+        v.truncate(100);
+        assert_eq!(*v.to_bool_slice(), bools[..5]);
+    }
+
+
+    #[test]
+    fn check_grow() {
+
+        let mut v = BitVec::from_bool_slice(&[true, false, true]);
+        v.grow(70, true);
+
+        let mut expected = vec![true, false, true];
+        expected.extend(vec![true; 70]);
+
+        assert_eq!(*v.to_bool_slice(), *expected);
+    }
+
+
+    #[test]
+    fn check_from_iterator_and_extend() {
+
+        let bools = [true, false, false, true, true, false, true];
+
+        let mut v: BitVec = bools.iter().copied().collect();
+        assert_eq!(*v.to_bool_slice(), bools);
+
+        v.extend([false, true, true]);
+
+        let mut expected = bools.to_vec();
+        expected.extend([false, true, true]);
+
+        assert_eq!(*v.to_bool_slice(), *expected);
+    }
+
+
+    #[test]
+    fn check_deserialize_errors() {
+
+        assert_eq!(BitVec::deserialize(&[]).unwrap_err(), DeserializeError::Empty);
+        assert_eq!(BitView::deserialize(&[]).unwrap_err(), DeserializeError::Empty);
+
+        assert_eq!(BitVec::deserialize(&[8]).unwrap_err(), DeserializeError::InvalidPadding);
+        assert_eq!(BitView::deserialize(&[8]).unwrap_err(), DeserializeError::InvalidPadding);
+
+        assert_eq!(BitVec::deserialize(&[1]).unwrap_err(), DeserializeError::PaddingWithoutData);
+        assert_eq!(BitView::deserialize(&[1]).unwrap_err(), DeserializeError::PaddingWithoutData);
+
+        assert!(BitVec::deserialize(&[0]).is_ok());
+        assert!(BitVec::deserialize(&[3, 0xff]).is_ok());
+    }
+
+
+    #[test]
+    fn check_try_from_padded_bytes() {
+
+        assert!(BitView::try_from_padded_bytes(&[], 0).is_ok());
+        assert_eq!(BitView::try_from_padded_bytes(&[], 1).unwrap_err(), DeserializeError::PaddingWithoutData);
+        assert_eq!(BitView::try_from_padded_bytes(&[0xff], 8).unwrap_err(), DeserializeError::InvalidPadding);
+        assert!(BitView::try_from_padded_bytes(&[0xff], 3).is_ok());
+    }
+
 }
 